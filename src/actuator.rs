@@ -0,0 +1,18 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+use crate::errors::*;
+
+
+/// A single normalized `[-1.0, 1.0]` output channel, implemented by whatever the car
+/// actually uses to command steering/drivetrain hardware (software PWM, CAN, ...), so the
+/// rest of the node doesn't need to care which one is in play.
+pub trait ActuatorOutput {
+    fn output(&mut self, value: f64) -> Result<()>;
+
+    /// The duty cycles most recently resolved onto the backend's two channels, for
+    /// PWM-based backends to report to the state publisher. Non-PWM backends (e.g. CAN)
+    /// have no duty cycle of their own, so this defaults to `(0.0, 0.0)`.
+    fn duty_cycles(&self) -> (f64, f64) {
+        (0.0, 0.0)
+    }
+}
@@ -0,0 +1,38 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+use socketcan::{CANFrame, CANSocket};
+
+use crate::actuator::ActuatorOutput;
+use crate::errors::*;
+
+
+/// Drives a normalized `[-1.0, 1.0]` command out over CAN instead of GPIO PWM: `value` is
+/// scaled to an `i16` and sent little-endian in the first two bytes of a fixed-id frame,
+/// the same layout used by the car's CAN-based steering/throttle actuators.
+pub struct CanActuator {
+    socket: CANSocket,
+    frame_id: u32,
+}
+
+impl CanActuator {
+    pub fn new(interface: &str, frame_id: u32) -> Result<Self> {
+        let socket = CANSocket::open(interface)?;
+        Ok(CanActuator { socket, frame_id })
+    }
+}
+
+impl ActuatorOutput for CanActuator {
+    fn output(&mut self, value: f64) -> Result<()> {
+        let value = value.max(-1.0).min(1.0);
+        let raw = (value * i16::MAX as f64) as i16;
+
+        let mut data = [0u8; 2];
+        data.copy_from_slice(&raw.to_le_bytes());
+
+        let frame = CANFrame::new(self.frame_id, &data, false, false)
+            .chain_err(|| "failed to build CAN frame")?;
+        self.socket.write_frame(&frame)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+use crate::errors::*;
+
+
+// Fallback values, used when a parameter isn't set on the ROS parameter server.
+const DEFAULT_DRIVETRAIN_PWM0: u8 = 5;
+const DEFAULT_DRIVETRAIN_PWM1: u8 = 6;
+const DEFAULT_DRIVETRAIN_PWM_FREQ: f64 = 50.0;
+const DEFAULT_DRIVETRAIN_MIN_DUTY_CYCLE: f64 = 0.15;
+
+const DEFAULT_STEERING_PWM0: u8 = 12;
+const DEFAULT_STEERING_PWM1: u8 = 13;
+const DEFAULT_STEERING_PWM_FREQ: f64 = 50.0;
+const DEFAULT_STEERING_MIN_DUTY_CYCLE: f64 = 0.2;
+
+
+/// PWM pin assignments and tuning, resolved from the ROS parameter server at startup.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub drivetrain_pwm0: u8,
+    pub drivetrain_pwm1: u8,
+    pub drivetrain_pwm_freq: f64,
+    pub drivetrain_min_duty_cycle: f64,
+
+    pub steering_pwm0: u8,
+    pub steering_pwm1: u8,
+    pub steering_pwm_freq: f64,
+    pub steering_min_duty_cycle: f64,
+}
+
+impl Config {
+    /// Resolves a `Config` from the `~drivetrain/*` and `~steering/*` ROS parameters,
+    /// falling back to the hard-coded defaults for any parameter that isn't set, then
+    /// validates the result before handing it back.
+    pub fn from_params() -> Result<Self> {
+        let config = Config {
+            drivetrain_pwm0: rosrust::param("~drivetrain/pwm0").unwrap().get().unwrap_or(DEFAULT_DRIVETRAIN_PWM0),
+            drivetrain_pwm1: rosrust::param("~drivetrain/pwm1").unwrap().get().unwrap_or(DEFAULT_DRIVETRAIN_PWM1),
+            drivetrain_pwm_freq: rosrust::param("~drivetrain/pwm_freq").unwrap().get().unwrap_or(DEFAULT_DRIVETRAIN_PWM_FREQ),
+            drivetrain_min_duty_cycle: rosrust::param("~drivetrain/min_duty_cycle").unwrap().get().unwrap_or(DEFAULT_DRIVETRAIN_MIN_DUTY_CYCLE),
+
+            steering_pwm0: rosrust::param("~steering/pwm0").unwrap().get().unwrap_or(DEFAULT_STEERING_PWM0),
+            steering_pwm1: rosrust::param("~steering/pwm1").unwrap().get().unwrap_or(DEFAULT_STEERING_PWM1),
+            steering_pwm_freq: rosrust::param("~steering/pwm_freq").unwrap().get().unwrap_or(DEFAULT_STEERING_PWM_FREQ),
+            steering_min_duty_cycle: rosrust::param("~steering/min_duty_cycle").unwrap().get().unwrap_or(DEFAULT_STEERING_MIN_DUTY_CYCLE),
+        };
+
+        config.validate()?;
+
+        rosrust::ros_info!("Drivetrain PWM pins: {}, {} @ {} Hz, min duty cycle {}",
+            config.drivetrain_pwm0, config.drivetrain_pwm1, config.drivetrain_pwm_freq, config.drivetrain_min_duty_cycle);
+        rosrust::ros_info!("Steering PWM pins: {}, {} @ {} Hz, min duty cycle {}",
+            config.steering_pwm0, config.steering_pwm1, config.steering_pwm_freq, config.steering_min_duty_cycle);
+
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.drivetrain_pwm_freq <= 0.0 {
+            bail!("drivetrain/pwm_freq must be > 0, got {}", self.drivetrain_pwm_freq);
+        }
+        if self.steering_pwm_freq <= 0.0 {
+            bail!("steering/pwm_freq must be > 0, got {}", self.steering_pwm_freq);
+        }
+
+        if !(self.drivetrain_min_duty_cycle > 0.0 && self.drivetrain_min_duty_cycle < 1.0) {
+            bail!("drivetrain/min_duty_cycle must be in (0, 1), got {}", self.drivetrain_min_duty_cycle);
+        }
+        if !(self.steering_min_duty_cycle > 0.0 && self.steering_min_duty_cycle < 1.0) {
+            bail!("steering/min_duty_cycle must be in (0, 1), got {}", self.steering_min_duty_cycle);
+        }
+
+        if self.drivetrain_pwm0 == self.drivetrain_pwm1 {
+            bail!("drivetrain/pwm0 and drivetrain/pwm1 must be distinct pins, both are {}", self.drivetrain_pwm0);
+        }
+        if self.steering_pwm0 == self.steering_pwm1 {
+            bail!("steering/pwm0 and steering/pwm1 must be distinct pins, both are {}", self.steering_pwm0);
+        }
+
+        let pins = [self.drivetrain_pwm0, self.drivetrain_pwm1, self.steering_pwm0, self.steering_pwm1];
+        for i in 0..pins.len() {
+            for j in (i + 1)..pins.len() {
+                if pins[i] == pins[j] {
+                    bail!("drivetrain and steering PWM pins must all be distinct, pin {} is used twice", pins[i]);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> Config {
+        Config {
+            drivetrain_pwm0: DEFAULT_DRIVETRAIN_PWM0,
+            drivetrain_pwm1: DEFAULT_DRIVETRAIN_PWM1,
+            drivetrain_pwm_freq: DEFAULT_DRIVETRAIN_PWM_FREQ,
+            drivetrain_min_duty_cycle: DEFAULT_DRIVETRAIN_MIN_DUTY_CYCLE,
+            steering_pwm0: DEFAULT_STEERING_PWM0,
+            steering_pwm1: DEFAULT_STEERING_PWM1,
+            steering_pwm_freq: DEFAULT_STEERING_PWM_FREQ,
+            steering_min_duty_cycle: DEFAULT_STEERING_MIN_DUTY_CYCLE,
+        }
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        assert!(valid_config().validate().is_ok());
+    }
+
+    #[test]
+    fn min_duty_cycle_of_zero_is_rejected() {
+        let mut config = valid_config();
+        config.drivetrain_min_duty_cycle = 0.0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn min_duty_cycle_of_one_is_rejected() {
+        let mut config = valid_config();
+        config.steering_min_duty_cycle = 1.0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn duplicate_pins_across_channels_are_rejected() {
+        let mut config = valid_config();
+        config.steering_pwm0 = config.drivetrain_pwm0;
+        assert!(config.validate().is_err());
+    }
+}
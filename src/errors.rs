@@ -0,0 +1,10 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+
+error_chain! {
+    foreign_links {
+        Ros(rosrust::error::Error);
+        Gpio(rppal::gpio::Error);
+        Io(::std::io::Error);
+    }
+}
@@ -7,93 +7,445 @@
 #[macro_use]
 extern crate error_chain;
 
-mod errors;
+mod actuator;
+mod can;
+pub mod errors;
+mod config;
+mod pid;
 mod pwm;
+mod shaping;
+
+pub use config::Config;
+pub use pwm::DualSoftwarePwm;
 
 
 ////////////////////////////////////////////////////////////////////////////////
 
 
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::sync::{Arc, Mutex};
 
-use rosrust_msg::std_msgs;
+use rosrust_msg::{std_msgs, geometry_msgs};
 
+use actuator::ActuatorOutput;
+use can::CanActuator;
 use errors::*;
+use pid::Pid;
 use pwm::DualSoftwarePwm;
+use shaping::Shaper;
+
+
+// Default time a channel may go without a fresh command before the watchdog
+// forces it back to neutral.
+const DEFAULT_COMMAND_TIMEOUT: f64 = 0.2;
+const WATCHDOG_RATE: f64 = 20.0;
+
+// Ackermann conversion defaults, used when running in `twist` input mode.
+const DEFAULT_WHEELBASE: f64 = 0.26;
+const DEFAULT_MAX_STEERING_ANGLE: f64 = 0.6;
+
+// Closed-loop drivetrain speed control defaults, used when `~drivetrain/closed_loop` is set.
+const DEFAULT_PID_INTEGRAL_LIMIT: f64 = 1.0;
+
+// Rate at which the steering/drivetrain command-shaping and (optional) PID control loops run.
+const ACTUATOR_CONTROL_RATE: f64 = 50.0;
+
+// Default rate for the `/tiger_car/state` feedback publisher.
+const DEFAULT_STATE_RATE: f64 = 10.0;
+
+// Command-shaping defaults: a low-pass filter time constant and a max slew rate (units per
+// second), applied to raw setpoints before they reach the actuators.
+const DEFAULT_STEERING_TAU: f64 = 0.04;
+const DEFAULT_STEERING_MAX_RATE: f64 = 10.0;
+const DEFAULT_DRIVETRAIN_TAU: f64 = 0.04;
+const DEFAULT_DRIVETRAIN_MAX_RATE: f64 = 2.0;
+
+
+/// Records a raw drivetrain target in `setpoint`. The actual PWM output happens in the
+/// drivetrain control loop in `run()`, which shapes this target (and, if closed-loop
+/// control is enabled, runs it through a PID) before writing it out.
+fn command_drivetrain(value: f64, setpoint: &Arc<Mutex<f64>>) {
+    *setpoint.lock().unwrap() = value;
+}
+
+
+/// Records a raw steering target in `cmd`. The actual PWM output happens in the steering
+/// control loop in `run()`, which shapes this target before writing it out.
+fn command_steering(value: f64, cmd: &Arc<Mutex<f64>>) {
+    *cmd.lock().unwrap() = value;
+}
+
+
+/// Converts a `Twist` into normalized `(steering, drivetrain)` setpoints via Ackermann
+/// steering geometry, clamping the resulting steering angle to `max_steer`.
+fn twist_to_steering_drivetrain(twist: &geometry_msgs::Twist, wheelbase: f64, max_steer: f64) -> (f64, f64) {
+    let speed = twist.linear.x;
+
+    let steering_angle = if speed.abs() < 1e-3 {
+        0.0
+    } else {
+        (wheelbase * twist.angular.z / speed).atan()
+    };
+    let steering_angle = steering_angle.max(-max_steer).min(max_steer);
+
+    let steering = (steering_angle / max_steer).max(-1.0).min(1.0);
+    let drivetrain = speed.max(-1.0).min(1.0);
+
+    (steering, drivetrain)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn twist(linear_x: f64, angular_z: f64) -> geometry_msgs::Twist {
+        let mut twist = geometry_msgs::Twist::default();
+        twist.linear.x = linear_x;
+        twist.angular.z = angular_z;
+        twist
+    }
 
+    #[test]
+    fn straight_line_has_zero_steering() {
+        let (steering, drivetrain) = twist_to_steering_drivetrain(&twist(0.5, 0.0), 0.26, 0.6);
+        assert_eq!(steering, 0.0);
+        assert_eq!(drivetrain, 0.5);
+    }
 
-// TODO: get parameters instead of hard coding
-const DRIVETRAIN_PWM0: u8 = 5;
-const DRIVETRAIN_PWM1: u8 = 6;
+    #[test]
+    fn zero_speed_has_zero_steering_regardless_of_turn_rate() {
+        let (steering, drivetrain) = twist_to_steering_drivetrain(&twist(0.0, 2.0), 0.26, 0.6);
+        assert_eq!(steering, 0.0);
+        assert_eq!(drivetrain, 0.0);
+    }
 
-const DRIVETRAIN_PWM_FREQ: f64 = 50.0;
-const DRIVETRAIN_MIN_DUTY_CYCLE: f64 = 0.15;
+    #[test]
+    fn steering_is_clamped_to_plus_minus_one() {
+        let (steering, _) = twist_to_steering_drivetrain(&twist(0.1, 10.0), 0.26, 0.6);
+        assert_eq!(steering, 1.0);
 
-const STEERING_PWM0: u8 = 12;
-const STEERING_PWM1: u8 = 13;
+        let (steering, _) = twist_to_steering_drivetrain(&twist(0.1, -10.0), 0.26, 0.6);
+        assert_eq!(steering, -1.0);
+    }
 
-const STEERING_PWM_FREQ: f64 = 50.0;
-const STEERING_MIN_DUTY_CYCLE: f64 = 0.2;
+    #[test]
+    fn drivetrain_is_clamped_to_plus_minus_one() {
+        let (_, drivetrain) = twist_to_steering_drivetrain(&twist(5.0, 0.0), 0.26, 0.6);
+        assert_eq!(drivetrain, 1.0);
+
+        let (_, drivetrain) = twist_to_steering_drivetrain(&twist(-5.0, 0.0), 0.26, 0.6);
+        assert_eq!(drivetrain, -1.0);
+    }
+}
 
 
 pub fn run() -> Result<()> {
 
     let log_names = rosrust::param("~log_names").unwrap().get().unwrap_or(false);
+    let command_timeout = Duration::from_secs_f64(
+        rosrust::param("~command_timeout").unwrap().get().unwrap_or(DEFAULT_COMMAND_TIMEOUT)
+    );
+    let input_mode: String = rosrust::param("~input_mode").unwrap().get().unwrap_or_else(|_| "independent".to_string());
+    let wheelbase: f64 = rosrust::param("~wheelbase").unwrap().get().unwrap_or(DEFAULT_WHEELBASE);
+    let max_steering_angle: f64 = rosrust::param("~max_steering_angle").unwrap().get().unwrap_or(DEFAULT_MAX_STEERING_ANGLE);
+    let config = Config::from_params()?;
+
+    let drivetrain_closed_loop: bool = rosrust::param("~drivetrain/closed_loop").unwrap().get().unwrap_or(false);
+    let pid_kp: f64 = rosrust::param("~drivetrain/pid/kp").unwrap().get().unwrap_or(1.0);
+    let pid_ki: f64 = rosrust::param("~drivetrain/pid/ki").unwrap().get().unwrap_or(0.0);
+    let pid_kd: f64 = rosrust::param("~drivetrain/pid/kd").unwrap().get().unwrap_or(0.0);
+    let pid_integral_limit: f64 = rosrust::param("~drivetrain/pid/integral_limit").unwrap().get().unwrap_or(DEFAULT_PID_INTEGRAL_LIMIT);
+    let state_rate: f64 = rosrust::param("~state_rate").unwrap().get().unwrap_or(DEFAULT_STATE_RATE);
+
+    let steering_shaping_enabled: bool = rosrust::param("~steering/shaping_enabled").unwrap().get().unwrap_or(true);
+    let steering_tau: f64 = rosrust::param("~steering/tau").unwrap().get().unwrap_or(DEFAULT_STEERING_TAU);
+    let steering_max_rate: f64 = rosrust::param("~steering/max_rate").unwrap().get().unwrap_or(DEFAULT_STEERING_MAX_RATE);
+    let drivetrain_shaping_enabled: bool = rosrust::param("~drivetrain/shaping_enabled").unwrap().get().unwrap_or(true);
+    let drivetrain_tau: f64 = rosrust::param("~drivetrain/tau").unwrap().get().unwrap_or(DEFAULT_DRIVETRAIN_TAU);
+    let drivetrain_max_rate: f64 = rosrust::param("~drivetrain/max_rate").unwrap().get().unwrap_or(DEFAULT_DRIVETRAIN_MAX_RATE);
+
+    // Output backend selection: "pwm" (the default) drives the channel's GPIO pins directly
+    // as software PWM, "can" sends it out as a CAN frame instead for cars wired that way.
+    let steering_output_backend: String = rosrust::param("~steering/output_backend").unwrap().get().unwrap_or_else(|_| "pwm".to_string());
+    let drivetrain_output_backend: String = rosrust::param("~drivetrain/output_backend").unwrap().get().unwrap_or_else(|_| "pwm".to_string());
+    let steering_can_interface: String = rosrust::param("~steering/can/interface").unwrap().get().unwrap_or_else(|_| "can0".to_string());
+    let steering_can_frame_id: i32 = rosrust::param("~steering/can/frame_id").unwrap().get().unwrap_or(0x100);
+    let drivetrain_can_interface: String = rosrust::param("~drivetrain/can/interface").unwrap().get().unwrap_or_else(|_| "can0".to_string());
+    let drivetrain_can_frame_id: i32 = rosrust::param("~drivetrain/can/frame_id").unwrap().get().unwrap_or(0x101);
 
     rosrust::ros_info!("Starting tiger_car");
 
 
-    let steering = Arc::new(Mutex::new(
-        DualSoftwarePwm::new(
-            STEERING_PWM0,
-            STEERING_PWM1,
-            STEERING_PWM_FREQ,
-            STEERING_MIN_DUTY_CYCLE,
-        ).unwrap()
+    let steering: Arc<Mutex<Box<dyn ActuatorOutput + Send>>> = Arc::new(Mutex::new(
+        match steering_output_backend.as_str() {
+            "can" => Box::new(CanActuator::new(&steering_can_interface, steering_can_frame_id as u32)?) as Box<dyn ActuatorOutput + Send>,
+            _ => Box::new(DualSoftwarePwm::new(
+                config.steering_pwm0,
+                config.steering_pwm1,
+                config.steering_pwm_freq,
+                config.steering_min_duty_cycle,
+            )?) as Box<dyn ActuatorOutput + Send>,
+        }
     ));
 
-    let drivetrain = Arc::new(Mutex::new(
-        DualSoftwarePwm::new(
-            DRIVETRAIN_PWM0,
-            DRIVETRAIN_PWM1,
-            DRIVETRAIN_PWM_FREQ,
-            DRIVETRAIN_MIN_DUTY_CYCLE,
-        ).unwrap()
+    let drivetrain: Arc<Mutex<Box<dyn ActuatorOutput + Send>>> = Arc::new(Mutex::new(
+        match drivetrain_output_backend.as_str() {
+            "can" => Box::new(CanActuator::new(&drivetrain_can_interface, drivetrain_can_frame_id as u32)?) as Box<dyn ActuatorOutput + Send>,
+            _ => Box::new(DualSoftwarePwm::new(
+                config.drivetrain_pwm0,
+                config.drivetrain_pwm1,
+                config.drivetrain_pwm_freq,
+                config.drivetrain_min_duty_cycle,
+            )?) as Box<dyn ActuatorOutput + Send>,
+        }
     ));
 
-    // Subscriptions
-    let steering_subscriber = rosrust::subscribe(
-        "/tiger_car/steer",
-        8,
-        move |v: std_msgs::Float64| {
-            rosrust::ros_info!("Steering Received: {}", v.data);
-            let result = steering.lock().unwrap().output(v.data);
-            if result.is_err() {
-                rosrust::ros_err!("Steering Error: {}", result.unwrap_err());
+    // Last time each channel received a command, watched by the watchdog thread below.
+    let steering_last_update = Arc::new(Mutex::new(Instant::now()));
+    let drivetrain_last_update = Arc::new(Mutex::new(Instant::now()));
+
+    // Velocity setpoint and measured wheel speed for closed-loop drivetrain control; also
+    // doubles as the last commanded value reported by the state publisher below.
+    let drivetrain_setpoint = Arc::new(Mutex::new(0.0_f64));
+    let drivetrain_measured = Arc::new(Mutex::new(0.0_f64));
+
+    // Last commanded steering value, reported by the state publisher below.
+    let steering_cmd = Arc::new(Mutex::new(0.0_f64));
+
+    // Subscriptions. In "independent" mode (the default) steering and drivetrain are
+    // driven by their own Float64 topics; in "twist" mode both are derived from a single
+    // cmd_vel Twist via Ackermann geometry, for compatibility with nav stacks.
+    let mut subscribers: Vec<(&'static str, rosrust::Subscriber)> = if input_mode == "twist" {
+        let steering_last_update = steering_last_update.clone();
+        let drivetrain_last_update = drivetrain_last_update.clone();
+        let drivetrain_setpoint = drivetrain_setpoint.clone();
+        let steering_cmd = steering_cmd.clone();
+        let cmd_vel_subscriber = rosrust::subscribe(
+            "/tiger_car/cmd_vel",
+            8,
+            move |v: geometry_msgs::Twist| {
+                let (steer, drive) = twist_to_steering_drivetrain(&v, wheelbase, max_steering_angle);
+                rosrust::ros_info!("Cmd_vel Received: linear.x={} angular.z={} -> steer={:.3} drive={:.3}", v.linear.x, v.angular.z, steer, drive);
+
+                let now = Instant::now();
+                *steering_last_update.lock().unwrap() = now;
+                *drivetrain_last_update.lock().unwrap() = now;
+
+                command_steering(steer, &steering_cmd);
+                command_drivetrain(drive, &drivetrain_setpoint);
             }
-        }
-    )?;
-
-    let drivetrain_subscriber = rosrust::subscribe(
-        "/tiger_car/speed",
-        8,
-        move |v: std_msgs::Float64| {
-            rosrust::ros_info!("Drivetrain Received: {}", v.data);
-            let result = drivetrain.lock().unwrap().output(v.data);
-            if result.is_err() {
-                rosrust::ros_err!("Drivetrain Error: {}", result.unwrap_err());
+        )?;
+        vec![("Cmd_vel", cmd_vel_subscriber)]
+    } else {
+        let steering_subscriber = {
+            let last_update = steering_last_update.clone();
+            let steering_cmd = steering_cmd.clone();
+            rosrust::subscribe(
+                "/tiger_car/control/steering",
+                8,
+                move |v: std_msgs::Float64| {
+                    rosrust::ros_info!("Steering Received: {}", v.data);
+                    *last_update.lock().unwrap() = Instant::now();
+                    command_steering(v.data, &steering_cmd);
+                }
+            )?
+        };
+
+        let drivetrain_subscriber = {
+            let last_update = drivetrain_last_update.clone();
+            let drivetrain_setpoint = drivetrain_setpoint.clone();
+            rosrust::subscribe(
+                "/tiger_car/control/drivetrain",
+                8,
+                move |v: std_msgs::Float64| {
+                    rosrust::ros_info!("Drivetrain Received: {}", v.data);
+                    *last_update.lock().unwrap() = Instant::now();
+                    command_drivetrain(v.data, &drivetrain_setpoint);
+                }
+            )?
+        };
+        vec![("Steering", steering_subscriber), ("Drivetrain", drivetrain_subscriber)]
+    };
+
+    // Optional closed-loop feedback: subscribe to measured wheel speed so the drivetrain
+    // control loop below can run a PID instead of passing its setpoint straight through.
+    if drivetrain_closed_loop {
+        let measured = drivetrain_measured.clone();
+        let feedback_subscriber = rosrust::subscribe(
+            "/tiger_car/control/drivetrain/feedback",
+            8,
+            move |v: std_msgs::Float64| {
+                *measured.lock().unwrap() = v.data;
             }
-        }
-    )?;
+        )?;
+        subscribers.push(("Drivetrain Feedback", feedback_subscriber));
+    }
+
+    // Steering control loop: low-pass filters and slew-limits the raw steering target
+    // before writing it out, so a step change on the input topic doesn't jerk the servo.
+    // This loop is the sole writer of the steering actuator: when its command has gone
+    // stale it snaps the shaper straight to neutral itself, rather than racing with the
+    // watchdog thread over who gets to write the actuator last.
+    {
+        let steering = steering.clone();
+        let steering_cmd = steering_cmd.clone();
+        let steering_last_update = steering_last_update.clone();
+        std::thread::spawn(move || {
+            let mut shaper = Shaper::new(steering_shaping_enabled, steering_tau, steering_max_rate);
+            let mut last_tick = Instant::now();
+            loop {
+                let now = Instant::now();
+                let dt = now.duration_since(last_tick).as_secs_f64();
+                last_tick = now;
+
+                let stale = now.duration_since(*steering_last_update.lock().unwrap()) > command_timeout;
+                let output = if stale {
+                    shaper.snap(0.0)
+                } else {
+                    let target = *steering_cmd.lock().unwrap();
+                    shaper.update(target, dt)
+                };
+
+                if let Err(e) = steering.lock().unwrap().output(output) {
+                    rosrust::ros_err!("Steering Error: {}", e);
+                }
+
+                sleep(Duration::from_secs_f64(1.0 / ACTUATOR_CONTROL_RATE));
+            }
+        });
+    }
+
+    // Drivetrain control loop: low-pass filters and slew-limits the raw drivetrain target,
+    // then (if `~drivetrain/closed_loop` is set) runs it through a PID against the measured
+    // wheel speed before writing it out; otherwise the shaped target is passed straight through.
+    // As with steering, this loop is the sole writer of the drivetrain actuator and owns
+    // forcing it to neutral once its command goes stale.
+    {
+        let drivetrain = drivetrain.clone();
+        let drivetrain_setpoint = drivetrain_setpoint.clone();
+        let drivetrain_measured = drivetrain_measured.clone();
+        let drivetrain_last_update = drivetrain_last_update.clone();
+        std::thread::spawn(move || {
+            let mut shaper = Shaper::new(drivetrain_shaping_enabled, drivetrain_tau, drivetrain_max_rate);
+            let mut pid = Pid::new(pid_kp, pid_ki, pid_kd, pid_integral_limit);
+            let mut last_tick = Instant::now();
+            let mut last_target = *drivetrain_setpoint.lock().unwrap();
+            loop {
+                let now = Instant::now();
+                let dt = now.duration_since(last_tick).as_secs_f64();
+                last_tick = now;
+
+                let stale = now.duration_since(*drivetrain_last_update.lock().unwrap()) > command_timeout;
+                let output = if stale {
+                    pid.reset();
+                    shaper.snap(0.0)
+                } else {
+                    let target = *drivetrain_setpoint.lock().unwrap();
+                    if target != last_target {
+                        pid.reset();
+                        last_target = target;
+                    }
+                    let shaped = shaper.update(target, dt);
+
+                    if drivetrain_closed_loop {
+                        let measured = *drivetrain_measured.lock().unwrap();
+                        pid.update(shaped, measured, dt)
+                    } else {
+                        shaped
+                    }
+                };
+
+                if let Err(e) = drivetrain.lock().unwrap().output(output) {
+                    rosrust::ros_err!("Drivetrain Error: {}", e);
+                }
+
+                sleep(Duration::from_secs_f64(1.0 / ACTUATOR_CONTROL_RATE));
+            }
+        });
+    }
+
+    // Watchdog: zero both channels' setpoints once their commands go stale, so the car
+    // doesn't keep driving on the last value it heard if a publisher dies. The actual
+    // forced-neutral write is owned by the steering/drivetrain control loops above (the
+    // only threads that touch the actuators); this thread just tracks staleness for
+    // logging and keeps the setpoints it reports to the state publisher honest.
+    {
+        let drivetrain_setpoint = drivetrain_setpoint.clone();
+        let steering_cmd = steering_cmd.clone();
+        std::thread::spawn(move || {
+            let mut steering_faulted = false;
+            let mut drivetrain_faulted = false;
+            loop {
+                let now = Instant::now();
+
+                let steering_stale = now.duration_since(*steering_last_update.lock().unwrap()) > command_timeout;
+                if steering_stale {
+                    *steering_cmd.lock().unwrap() = 0.0;
+                    rosrust::ros_warn_throttle!(1.0, "Steering watchdog: no command in over {:?}, forcing neutral", command_timeout);
+                    steering_faulted = true;
+                } else if steering_faulted {
+                    rosrust::ros_info!("Steering watchdog: fresh commands received, resuming passthrough");
+                    steering_faulted = false;
+                }
+
+                let drivetrain_stale = now.duration_since(*drivetrain_last_update.lock().unwrap()) > command_timeout;
+                if drivetrain_stale {
+                    *drivetrain_setpoint.lock().unwrap() = 0.0;
+                    rosrust::ros_warn_throttle!(1.0, "Drivetrain watchdog: no command in over {:?}, forcing neutral", command_timeout);
+                    drivetrain_faulted = true;
+                } else if drivetrain_faulted {
+                    rosrust::ros_info!("Drivetrain watchdog: fresh commands received, resuming passthrough");
+                    drivetrain_faulted = false;
+                }
+
+                sleep(Duration::from_secs_f64(1.0 / WATCHDOG_RATE));
+            }
+        });
+    }
+
+    // State feedback: publish the last commanded steering/speed alongside the duty cycles
+    // actually resolved onto the PWM channels, so downstream tools have a stamped trace of
+    // what the car did. The duty cycles are packed onto the otherwise-unused Twist fields:
+    // linear.{y,z} are the drivetrain pin0/pin1 duty cycles, angular.{x,y} are steering's.
+    {
+        let steering = steering.clone();
+        let drivetrain = drivetrain.clone();
+        let steering_cmd = steering_cmd.clone();
+        let drivetrain_setpoint = drivetrain_setpoint.clone();
+        let state_publisher = rosrust::publish("/tiger_car/state", 8)?;
+        std::thread::spawn(move || {
+            loop {
+                let (drivetrain_duty0, drivetrain_duty1) = drivetrain.lock().unwrap().duty_cycles();
+                let (steering_duty0, steering_duty1) = steering.lock().unwrap().duty_cycles();
+
+                let mut state = geometry_msgs::TwistStamped::default();
+                state.header.stamp = rosrust::now();
+                state.header.frame_id = "tiger_car".to_string();
+                state.twist.linear.x = *drivetrain_setpoint.lock().unwrap();
+                state.twist.linear.y = drivetrain_duty0;
+                state.twist.linear.z = drivetrain_duty1;
+                state.twist.angular.x = steering_duty0;
+                state.twist.angular.y = steering_duty1;
+                state.twist.angular.z = *steering_cmd.lock().unwrap();
+
+                if let Err(e) = state_publisher.send(state) {
+                    rosrust::ros_err!("State Publish Error: {}", e);
+                }
+
+                sleep(Duration::from_secs_f64(1.0 / state_rate));
+            }
+        });
+    }
 
     // Loop
     if log_names {
         let rate = rosrust::rate(1.0);
         while rosrust::is_ok() {
-            rosrust::ros_info!("Steering Publisher uris: {:?}", steering_subscriber.publisher_uris());
-            rosrust::ros_info!("Drivertrain Publisher uris: {:?}", drivetrain_subscriber.publisher_uris());
+            for (name, subscriber) in &subscribers {
+                rosrust::ros_info!("{} Publisher uris: {:?}", name, subscriber.publisher_uris());
+            }
             rate.sleep();
         }
     } else {
@@ -108,11 +460,12 @@ pub fn run() -> Result<()> {
 // Manual tests
 
 fn test_drivetrain_range() {
+    let config = Config::from_params().unwrap();
     let mut drivetrain = DualSoftwarePwm::new(
-        DRIVETRAIN_PWM0,
-        DRIVETRAIN_PWM1,
-        DRIVETRAIN_PWM_FREQ,
-        DRIVETRAIN_MIN_DUTY_CYCLE,
+        config.drivetrain_pwm0,
+        config.drivetrain_pwm1,
+        config.drivetrain_pwm_freq,
+        config.drivetrain_min_duty_cycle,
     ).unwrap();
 
     for i in -5..6 {
@@ -124,11 +477,12 @@ fn test_drivetrain_range() {
 }
 
 fn test_steering_range() {
+    let config = Config::from_params().unwrap();
     let mut steering = DualSoftwarePwm::new(
-        STEERING_PWM0,
-        STEERING_PWM1,
-        STEERING_PWM_FREQ,
-        STEERING_MIN_DUTY_CYCLE,
+        config.steering_pwm0,
+        config.steering_pwm1,
+        config.steering_pwm_freq,
+        config.steering_min_duty_cycle,
     ).unwrap();
 
     for i in -10..11 {
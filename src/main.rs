@@ -3,101 +3,33 @@
 use env_logger;
 use rosrust;
 use tiger_car_ros::*;
+use tiger_car_ros::errors::Result;
 
 use std::thread::sleep;
 use std::time::Duration;
-use std::sync::{Arc, Mutex};
 
 
-// Future parameters, defaults?
-const DRIVETRAIN_PWM0: u8 = 5;
-const DRIVETRAIN_PWM1: u8 = 6;
-
-const DRIVETRAIN_PWM_FREQ: f64 = 50.0;
-const DRIVETRAIN_MIN_DUTY_CYCLE: f64 = 0.15;
-
-const STEERING_PWM0: u8 = 12;
-const STEERING_PWM1: u8 = 13;
-
-const STEERING_PWM_FREQ: f64 = 50.0;
-const STEERING_MIN_DUTY_CYCLE: f64 = 0.2;
-
-
-fn main() {
+fn main() -> Result<()> {
 
     println!("Starting program");
 
     // Setup
     env_logger::init();
     rosrust::init("tiger_car");
-    let log_names = rosrust::param("~log_names").unwrap().get().unwrap_or(false);
-
-    rosrust::ros_info!("Starting tiger_car");
-
-    // TODO: get parameters
 
-    let steering = Arc::new(Mutex::new(DualSoftwarePwm::new(
-        STEERING_PWM0,
-        STEERING_PWM1,
-        STEERING_PWM_FREQ,
-        STEERING_MIN_DUTY_CYCLE,
-    ).unwrap()));
-
-    let drivetrain = Arc::new(Mutex::new(DualSoftwarePwm::new(
-        DRIVETRAIN_PWM0,
-        DRIVETRAIN_PWM1,
-        DRIVETRAIN_PWM_FREQ,
-        DRIVETRAIN_MIN_DUTY_CYCLE,
-    ).unwrap()));
-
-    // Subscriptions
-    let steering_subscriber = rosrust::subscribe(
-        "/tiger_car/control/steering",
-        8,
-        move |v: rosrust_msg::std_msgs::Float64| {
-            rosrust::ros_info!("Steering Received: {}", v.data);
-            let result = steering.lock().unwrap().output(v.data);
-            if result.is_err() {
-                rosrust::ros_err!("Steering Error: {}", result.unwrap_err());
-            }
-        }
-    ).unwrap();
-
-    let drivetrain_subscriber = rosrust::subscribe(
-        "/tiger_car/control/drivetrain",
-        8,
-        move |v: rosrust_msg::std_msgs::Float64| {
-            rosrust::ros_info!("Drivetrain Received: {}", v.data);
-            let result = drivetrain.lock().unwrap().output(v.data);
-            if result.is_err() {
-                rosrust::ros_err!("Drivetrain Error: {}", result.unwrap_err());
-            }
-        }
-    ).unwrap();
-
-    // Loop
-    if log_names {
-        let rate = rosrust::rate(1.0);
-        while rosrust::is_ok() {
-            rosrust::ros_info!("Steering Publisher uris: {:?}", steering_subscriber.publisher_uris());
-            rosrust::ros_info!("Drivertrain Publisher uris: {:?}", drivetrain_subscriber.publisher_uris());
-            rate.sleep();
-        }
-    } else {
-        // Block the thread until a shutdown signal is received
-        rosrust::spin();
-    }
+    tiger_car_ros::run()
 }
 
 
 // Manual tests
 
 fn test_drivetrain_range() {
+    let config = Config::from_params().unwrap();
     let mut drivetrain = DualSoftwarePwm::new(
-        DRIVETRAIN_PWM0,
-        DRIVETRAIN_PWM1,
-        DRIVETRAIN_PWM_FREQ,
-        DRIVETRAIN_MIN_DUTY_CYCLE,
+        config.drivetrain_pwm0,
+        config.drivetrain_pwm1,
+        config.drivetrain_pwm_freq,
+        config.drivetrain_min_duty_cycle,
     ).unwrap();
 
     for i in -5..6 {
@@ -109,11 +41,12 @@ fn test_drivetrain_range() {
 }
 
 fn test_steering_range() {
+    let config = Config::from_params().unwrap();
     let mut steering = DualSoftwarePwm::new(
-        STEERING_PWM0,
-        STEERING_PWM1,
-        STEERING_PWM_FREQ,
-        STEERING_MIN_DUTY_CYCLE,
+        config.steering_pwm0,
+        config.steering_pwm1,
+        config.steering_pwm_freq,
+        config.steering_min_duty_cycle,
     ).unwrap();
 
     for i in -10..11 {
@@ -0,0 +1,81 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+
+/// A textbook PID controller with integral anti-windup clamping, used to regulate a
+/// measured quantity (e.g. wheel speed) towards a setpoint by driving a `[-1.0, 1.0]`
+/// normalized actuator output.
+pub struct Pid {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    integral_limit: f64,
+    integral: f64,
+    prev_error: f64,
+}
+
+impl Pid {
+    pub fn new(kp: f64, ki: f64, kd: f64, integral_limit: f64) -> Self {
+        Pid {
+            kp,
+            ki,
+            kd,
+            integral_limit,
+            integral: 0.0,
+            prev_error: 0.0,
+        }
+    }
+
+    /// Clears the integral accumulator and derivative history. Call this whenever the
+    /// setpoint changes out from under the controller to avoid a derivative spike or
+    /// windup carried over from the previous target.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.prev_error = 0.0;
+    }
+
+    /// Runs one control tick and returns the new actuator output, clamped to `[-1.0, 1.0]`.
+    pub fn update(&mut self, setpoint: f64, measured: f64, dt: f64) -> f64 {
+        let error = setpoint - measured;
+
+        self.integral = (self.integral + error * dt)
+            .max(-self.integral_limit)
+            .min(self.integral_limit);
+
+        let derivative = if dt > 0.0 { (error - self.prev_error) / dt } else { 0.0 };
+        self.prev_error = error;
+
+        (self.kp * error + self.ki * self.integral + self.kd * derivative)
+            .max(-1.0)
+            .min(1.0)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_is_clamped_to_unit_range() {
+        let mut pid = Pid::new(100.0, 0.0, 0.0, 1.0);
+        assert_eq!(pid.update(1.0, 0.0, 0.1), 1.0);
+        assert_eq!(pid.update(-1.0, 0.0, 0.1), -1.0);
+    }
+
+    #[test]
+    fn integral_is_clamped_to_integral_limit() {
+        let mut pid = Pid::new(0.0, 1.0, 0.0, 0.5);
+        for _ in 0..100 {
+            pid.update(1.0, 0.0, 1.0);
+        }
+        assert_eq!(pid.update(1.0, 0.0, 1.0), 0.5);
+    }
+
+    #[test]
+    fn reset_clears_integral_and_derivative_history() {
+        let mut pid = Pid::new(0.0, 1.0, 1.0, 10.0);
+        pid.update(1.0, 0.0, 1.0);
+        pid.reset();
+        assert_eq!(pid.update(0.0, 0.0, 1.0), 0.0);
+    }
+}
@@ -0,0 +1,91 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+use rppal::gpio::{Gpio, OutputPin};
+
+use crate::actuator::ActuatorOutput;
+use crate::errors::*;
+
+
+/// Drives a normalized `[-1.0, 1.0]` command onto a pair of GPIO pins as software PWM:
+/// positive values raise the duty cycle on `pin0` and hold `pin1` low, negative values do
+/// the opposite, and the magnitude is mapped onto `[min_duty_cycle, 1.0]` so that small
+/// commands still produce a usable pulse width.
+pub struct DualSoftwarePwm {
+    pwm0: OutputPin,
+    pwm1: OutputPin,
+    freq: f64,
+    min_duty_cycle: f64,
+    duty_cycle0: f64,
+    duty_cycle1: f64,
+}
+
+impl DualSoftwarePwm {
+    pub fn new(pin0: u8, pin1: u8, freq: f64, min_duty_cycle: f64) -> Result<Self> {
+        let gpio = Gpio::new()?;
+        let mut pwm0 = gpio.get(pin0)?.into_output();
+        let mut pwm1 = gpio.get(pin1)?.into_output();
+
+        pwm0.clear_pwm()?;
+        pwm1.clear_pwm()?;
+
+        Ok(DualSoftwarePwm {
+            pwm0,
+            pwm1,
+            freq,
+            min_duty_cycle,
+            duty_cycle0: 0.0,
+            duty_cycle1: 0.0,
+        })
+    }
+
+    /// Outputs `value`, clamped to `[-1.0, 1.0]`, as a duty cycle on whichever channel
+    /// matches its sign, holding the other channel low.
+    pub fn output(&mut self, value: f64) -> Result<()> {
+        let value = value.max(-1.0).min(1.0);
+
+        let (duty0, duty1) = if value > 0.0 {
+            (self.min_duty_cycle + value * (1.0 - self.min_duty_cycle), 0.0)
+        } else if value < 0.0 {
+            (0.0, self.min_duty_cycle + (-value) * (1.0 - self.min_duty_cycle))
+        } else {
+            (0.0, 0.0)
+        };
+
+        if duty0 > 0.0 {
+            self.pwm0.set_pwm_frequency(self.freq, duty0)?;
+        } else {
+            self.pwm0.clear_pwm()?;
+        }
+
+        if duty1 > 0.0 {
+            self.pwm1.set_pwm_frequency(self.freq, duty1)?;
+        } else {
+            self.pwm1.clear_pwm()?;
+        }
+
+        self.duty_cycle0 = duty0;
+        self.duty_cycle1 = duty1;
+
+        Ok(())
+    }
+
+    /// The duty cycle most recently resolved onto `pin0` by `output`.
+    pub fn duty_cycle0(&self) -> f64 {
+        self.duty_cycle0
+    }
+
+    /// The duty cycle most recently resolved onto `pin1` by `output`.
+    pub fn duty_cycle1(&self) -> f64 {
+        self.duty_cycle1
+    }
+}
+
+impl ActuatorOutput for DualSoftwarePwm {
+    fn output(&mut self, value: f64) -> Result<()> {
+        DualSoftwarePwm::output(self, value)
+    }
+
+    fn duty_cycles(&self) -> (f64, f64) {
+        (self.duty_cycle0(), self.duty_cycle1())
+    }
+}
@@ -0,0 +1,88 @@
+/* Copyright (C) 2020 Dylan Staatz - All Rights Reserved. */
+
+
+/// Shapes a raw target value into something safe to hand straight to an actuator: a
+/// first-order low-pass filter smooths out noise and steps, then a per-tick slew limit
+/// caps how fast the output may move, regardless of how big a step the filter wants to take.
+pub struct Shaper {
+    enabled: bool,
+    tau: f64,
+    max_rate: f64,
+    filtered: f64,
+}
+
+impl Shaper {
+    pub fn new(enabled: bool, tau: f64, max_rate: f64) -> Self {
+        Shaper {
+            enabled,
+            tau,
+            max_rate,
+            filtered: 0.0,
+        }
+    }
+
+    /// Advances the shaper by `dt` seconds towards `target`, returning the new output.
+    pub fn update(&mut self, target: f64, dt: f64) -> f64 {
+        if !self.enabled || dt <= 0.0 {
+            self.filtered = target;
+            return self.filtered;
+        }
+
+        // A gain above 1 (dt > tau) would overshoot the target and oscillate, so cap it.
+        let alpha = (dt / self.tau).min(1.0);
+        let lpf = self.filtered + alpha * (target - self.filtered);
+        let max_step = self.max_rate * dt;
+        let delta = (lpf - self.filtered).max(-max_step).min(max_step);
+        self.filtered += delta;
+
+        self.filtered
+    }
+
+    /// Immediately snaps the output to `value`, bypassing the low-pass/slew limiting.
+    /// Used to force an instant neutral (e.g. from a watchdog) without a second thread
+    /// writing the actuator out from under the control loop that owns it.
+    pub fn snap(&mut self, value: f64) -> f64 {
+        self.filtered = value;
+        self.filtered
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_passes_target_through_immediately() {
+        let mut shaper = Shaper::new(false, 0.01, 0.1);
+        assert_eq!(shaper.update(1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn non_positive_dt_passes_target_through_immediately() {
+        let mut shaper = Shaper::new(true, 0.01, 0.1);
+        assert_eq!(shaper.update(1.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn slew_limit_caps_the_per_tick_step() {
+        let mut shaper = Shaper::new(true, 0.001, 1.0);
+        let output = shaper.update(1.0, 0.1);
+        assert_eq!(output, 0.1);
+    }
+
+    #[test]
+    fn converges_to_target_once_slew_limited_steps_accumulate() {
+        let mut shaper = Shaper::new(true, 0.001, 1.0);
+        for _ in 0..100 {
+            shaper.update(1.0, 0.1);
+        }
+        assert_eq!(shaper.update(1.0, 0.1), 1.0);
+    }
+
+    #[test]
+    fn snap_bypasses_shaping() {
+        let mut shaper = Shaper::new(true, 0.001, 0.01);
+        assert_eq!(shaper.snap(0.0), 0.0);
+    }
+}